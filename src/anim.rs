@@ -0,0 +1,25 @@
+//! Turns a static set of paths into a spinning animation using native SVG
+//! SMIL: the paths are wrapped in a `<g>` and given an `animateTransform`
+//! that rotates the group a full turn around its center, looping forever.
+
+use svg::node::element::{AnimateTransform, Group, Path};
+use ultraviolet::Vec2;
+
+/// Wraps `paths` in a `<g>` that continuously rotates 360 degrees around
+/// `center` over `dur` (an SVG time value, e.g. `"8s"`).
+pub fn spin(paths: Vec<Path>, center: Vec2, dur: &str) -> Group {
+    let mut group = Group::new();
+    for path in paths {
+        group = group.add(path);
+    }
+
+    group.add(
+        AnimateTransform::new()
+            .set("attributeName", "transform")
+            .set("type", "rotate")
+            .set("from", format!("0 {} {}", center.x, center.y))
+            .set("to", format!("360 {} {}", center.x, center.y))
+            .set("dur", dur)
+            .set("repeatCount", "indefinite"),
+    )
+}
@@ -0,0 +1,364 @@
+//! A gradient representation that is richer than raw `LinearGradient`/
+//! `RadialGradient` SVG nodes: it tracks its geometry as data (so it can be
+//! built programmatically or parsed) and supports an optional affine
+//! `gradientTransform`, letting one gradient definition be reused moved,
+//! rotated or scaled across the segmented star. The transform has no shear
+//! component, so it cannot skew a gradient.
+
+use std::fmt;
+
+use ultraviolet::{Rotor2, Vec2};
+
+use svg::node::element::{LinearGradient, RadialGradient, Stop};
+use svg::node::Node;
+
+/// A single `<stop>` in a gradient's color ramp.
+#[derive(Debug, Clone)]
+pub struct ColorStop {
+    pub color: String,
+    /// Fraction of the way along the gradient, in `0.0..=1.0`.
+    pub offset: f32,
+}
+
+impl ColorStop {
+    pub fn new(color: impl Into<String>, offset: f32) -> Self {
+        ColorStop {
+            color: color.into(),
+            offset,
+        }
+    }
+
+    fn to_node(&self) -> Stop {
+        Stop::new()
+            .set("stop-color", self.color.as_str())
+            .set("offset", format!("{}%", self.offset * 100.0))
+    }
+}
+
+/// The shape a gradient is painted along.
+#[derive(Debug, Clone, Copy)]
+pub enum GradientGeometry {
+    Linear {
+        start: Vec2,
+        end: Vec2,
+    },
+    Radial {
+        start_circle: (Vec2, f32),
+        end_circle: (Vec2, f32),
+    },
+}
+
+/// A translation + rotation + scale affine transform, serialized as an SVG
+/// `gradientTransform="matrix(a b c d e f)"`.
+#[derive(Debug, Clone, Copy)]
+pub struct GradientTransform {
+    pub translation: Vec2,
+    pub rotation: Rotor2,
+    pub scale: Vec2,
+}
+
+impl Default for GradientTransform {
+    fn default() -> Self {
+        GradientTransform {
+            translation: Vec2::zero(),
+            rotation: Rotor2::identity(),
+            scale: Vec2::one(),
+        }
+    }
+}
+
+impl GradientTransform {
+    fn is_identity(&self) -> bool {
+        self.translation == Vec2::zero()
+            && self.rotation == Rotor2::identity()
+            && self.scale == Vec2::one()
+    }
+
+    /// Renders this transform as an SVG `matrix(a b c d e f)` string.
+    fn to_matrix_string(self) -> String {
+        let rotation = self.rotation.into_matrix();
+        let a = rotation.cols[0].x * self.scale.x;
+        let b = rotation.cols[0].y * self.scale.x;
+        let c = rotation.cols[1].x * self.scale.y;
+        let d = rotation.cols[1].y * self.scale.y;
+        format!(
+            "matrix({} {} {} {} {} {})",
+            a, b, c, d, self.translation.x, self.translation.y
+        )
+    }
+}
+
+/// A gradient definition: its geometry, color stops, and an optional
+/// transform, ready to be emitted as a `<linearGradient>`/`<radialGradient>`
+/// node inside a `<defs>` block.
+#[derive(Debug, Clone)]
+pub struct Gradient {
+    pub id: String,
+    pub geometry: GradientGeometry,
+    pub stops: Vec<ColorStop>,
+    pub transform: Option<GradientTransform>,
+}
+
+impl Gradient {
+    pub fn new(id: impl Into<String>, geometry: GradientGeometry, stops: Vec<ColorStop>) -> Self {
+        Gradient {
+            id: id.into(),
+            geometry,
+            stops,
+            transform: None,
+        }
+    }
+
+    pub fn with_transform(mut self, transform: GradientTransform) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+
+    /// Builds the `<linearGradient>` or `<radialGradient>` SVG node for this
+    /// gradient, ready to `.add()` into a `<defs>` block.
+    pub fn to_node(&self) -> Box<dyn Node> {
+        let mut node: Box<dyn Node> = match self.geometry {
+            GradientGeometry::Linear { start, end } => Box::new(
+                LinearGradient::new()
+                    .set("id", self.id.as_str())
+                    .set("gradientUnits", "userSpaceOnUse")
+                    .set("x1", start.x)
+                    .set("y1", start.y)
+                    .set("x2", end.x)
+                    .set("y2", end.y),
+            ),
+            GradientGeometry::Radial {
+                start_circle: (start_center, start_radius),
+                end_circle: (end_center, end_radius),
+            } => Box::new(
+                RadialGradient::new()
+                    .set("id", self.id.as_str())
+                    .set("gradientUnits", "userSpaceOnUse")
+                    .set("fx", start_center.x)
+                    .set("fy", start_center.y)
+                    .set("fr", start_radius)
+                    .set("cx", end_center.x)
+                    .set("cy", end_center.y)
+                    .set("r", end_radius),
+            ),
+        };
+
+        // Nested rather than a let-chain so this doesn't require edition 2024.
+        #[allow(clippy::collapsible_if)]
+        if let Some(transform) = self.transform {
+            if !transform.is_identity() {
+                if let Some(attributes) = node.get_attributes_mut() {
+                    attributes.insert(
+                        "gradientTransform".to_string(),
+                        transform.to_matrix_string().into(),
+                    );
+                }
+            }
+        }
+
+        if let Some(children) = node.get_children_mut() {
+            for stop in &self.stops {
+                children.push(Box::new(stop.to_node()));
+            }
+        }
+
+        node
+    }
+}
+
+/// An error encountered while parsing a CSS-style gradient string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GradientParseError {
+    /// The spec has a direction but no color stops.
+    MissingStops,
+    /// The leading direction token is neither `linear [<angle>deg]` nor `radial`.
+    UnknownDirection(String),
+    /// A stop has no color token before its offset.
+    MissingColor(String),
+    /// A stop's trailing offset isn't a `<number>%`.
+    InvalidOffset(String),
+}
+
+impl fmt::Display for GradientParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GradientParseError::MissingStops => write!(f, "gradient spec has no color stops"),
+            GradientParseError::UnknownDirection(token) => {
+                write!(f, "unrecognized gradient direction: {token:?}")
+            }
+            GradientParseError::MissingColor(stop) => {
+                write!(f, "gradient stop is missing a color: {stop:?}")
+            }
+            GradientParseError::InvalidOffset(offset) => {
+                write!(f, "gradient stop has an invalid percentage offset: {offset:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GradientParseError {}
+
+/// Splits `spec` on top-level commas, treating commas inside `(...)` as part
+/// of the surrounding token so that `rgb(...)`/`hsl(...)` color functions
+/// aren't split mid-argument.
+fn split_top_level(spec: &str) -> Vec<&str> {
+    let mut segments = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in spec.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                segments.push(spec[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    segments.push(spec[start..].trim());
+    segments
+}
+
+/// Parses the leading direction token (`linear [<angle>deg]` or `radial`)
+/// into a unit-space `GradientGeometry` — a radius-1 circle at the origin,
+/// or a unit vector from the origin — for the caller to scale and position
+/// with a [`GradientTransform`].
+fn parse_direction(token: &str) -> Result<GradientGeometry, GradientParseError> {
+    let mut words = token.split_whitespace();
+    match words.next() {
+        Some(keyword) if keyword.eq_ignore_ascii_case("radial") => Ok(GradientGeometry::Radial {
+            start_circle: (Vec2::zero(), 0.0),
+            end_circle: (Vec2::zero(), 1.0),
+        }),
+        Some(keyword) if keyword.eq_ignore_ascii_case("linear") => {
+            let angle_deg: f32 = match words.next() {
+                Some(angle_token) => angle_token
+                    .trim_end_matches("deg")
+                    .parse()
+                    .map_err(|_| GradientParseError::UnknownDirection(token.to_string()))?,
+                None => 0.0,
+            };
+            let angle = angle_deg.to_radians();
+            Ok(GradientGeometry::Linear {
+                start: Vec2::zero(),
+                end: Vec2::new(angle.sin(), angle.cos()),
+            })
+        }
+        _ => Err(GradientParseError::UnknownDirection(token.to_string())),
+    }
+}
+
+/// Parses a single stop, e.g. `"#dcb37e 0%"` or `"rgb(220, 179, 126) 50%"`,
+/// splitting on the last run of whitespace so that color functions with
+/// internal spaces are kept intact.
+fn parse_color_stop(stop: &str) -> Result<ColorStop, GradientParseError> {
+    let (color, offset) = stop
+        .trim()
+        .rsplit_once(char::is_whitespace)
+        .ok_or_else(|| GradientParseError::MissingColor(stop.to_string()))?;
+    let color = color.trim();
+    if color.is_empty() {
+        return Err(GradientParseError::MissingColor(stop.to_string()));
+    }
+    let percent = offset
+        .trim()
+        .strip_suffix('%')
+        .ok_or_else(|| GradientParseError::InvalidOffset(offset.to_string()))?;
+    let offset: f32 = percent
+        .parse()
+        .map_err(|_| GradientParseError::InvalidOffset(offset.to_string()))?;
+    Ok(ColorStop::new(color, offset / 100.0))
+}
+
+/// Parses a CSS-style gradient spec such as
+/// `"linear 36deg, #dcb37e 0%, #fefac9 50%"` or
+/// `"radial, #dcb37e 0%, #fefac9 100%"` into a [`Gradient`]. The geometry is
+/// in unit space (a unit vector from the origin for `linear`, a radius-1
+/// circle at the origin for `radial`) — apply a [`GradientTransform`] to
+/// position and scale it.
+pub fn parse_gradient(id: impl Into<String>, spec: &str) -> Result<Gradient, GradientParseError> {
+    let segments = split_top_level(spec);
+    let (direction, stops) = segments
+        .split_first()
+        .ok_or(GradientParseError::MissingStops)?;
+    let geometry = parse_direction(direction)?;
+    if stops.is_empty() {
+        return Err(GradientParseError::MissingStops);
+    }
+    let stops = stops
+        .iter()
+        .map(|stop| parse_color_stop(stop))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Gradient::new(id, geometry, stops))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_top_level_keeps_color_functions_intact() {
+        let segments = split_top_level("linear 36deg, rgb(220, 179, 126) 0%, hsl(50, 80%, 60%) 100%");
+        assert_eq!(
+            segments,
+            vec![
+                "linear 36deg",
+                "rgb(220, 179, 126) 0%",
+                "hsl(50, 80%, 60%) 100%",
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_color_stop_parses_color_and_offset() {
+        let stop = parse_color_stop("#dcb37e 25%").unwrap();
+        assert_eq!(stop.color, "#dcb37e");
+        assert_eq!(stop.offset, 0.25);
+    }
+
+    #[test]
+    fn parse_color_stop_keeps_color_function_spaces_intact() {
+        let stop = parse_color_stop("rgb(220, 179, 126) 50%").unwrap();
+        assert_eq!(stop.color, "rgb(220, 179, 126)");
+        assert_eq!(stop.offset, 0.5);
+    }
+
+    #[test]
+    fn parse_color_stop_rejects_missing_offset() {
+        let err = parse_color_stop("#dcb37e").unwrap_err();
+        assert_eq!(err, GradientParseError::MissingColor("#dcb37e".to_string()));
+    }
+
+    #[test]
+    fn parse_color_stop_rejects_malformed_offset() {
+        let err = parse_color_stop("#dcb37e 25").unwrap_err();
+        assert_eq!(err, GradientParseError::InvalidOffset("25".to_string()));
+    }
+
+    #[test]
+    fn parse_gradient_rejects_missing_stops() {
+        let err = parse_gradient("g", "linear 36deg").unwrap_err();
+        assert_eq!(err, GradientParseError::MissingStops);
+    }
+
+    #[test]
+    fn parse_gradient_rejects_unknown_direction() {
+        let err = parse_gradient("g", "conic, #dcb37e 0%").unwrap_err();
+        assert_eq!(err, GradientParseError::UnknownDirection("conic".to_string()));
+    }
+
+    #[test]
+    fn parse_gradient_parses_linear_spec() {
+        let gradient = parse_gradient("g", "linear 36deg, #dcb37e 0%, #fefac9 50%").unwrap();
+        assert!(matches!(gradient.geometry, GradientGeometry::Linear { .. }));
+        assert_eq!(gradient.stops.len(), 2);
+        assert_eq!(gradient.stops[1].offset, 0.5);
+    }
+
+    #[test]
+    fn parse_gradient_parses_radial_spec() {
+        let gradient = parse_gradient("g", "radial, #dcb37e 0%, #fefac9 100%").unwrap();
+        assert!(matches!(gradient.geometry, GradientGeometry::Radial { .. }));
+    }
+}
@@ -0,0 +1,147 @@
+//! Extrudes the crate's 2D star/circle geometry into a flat 3D prism and
+//! writes it out as a binary STL mesh, suitable for 3D printing a medallion
+//! of the logo.
+
+use std::fs::File;
+use std::io::{self, Write};
+
+use ultraviolet::{Vec2, Vec3};
+
+/// A single triangular facet of a mesh, with its outward face normal.
+struct Triangle {
+    normal: Vec3,
+    vertices: [Vec3; 3],
+}
+
+impl Triangle {
+    fn new(v0: Vec3, v1: Vec3, v2: Vec3) -> Self {
+        let normal = (v1 - v0).cross(v2 - v0).normalized();
+        Triangle {
+            normal,
+            vertices: [v0, v1, v2],
+        }
+    }
+}
+
+/// Extrudes a closed 2D polygon (as produced by `star_points`) into a flat
+/// prism of `extrude_depth`, fanning the top and bottom caps from `center`
+/// and connecting each boundary edge to its counterpart with a pair of side
+/// wall triangles.
+fn extrude_polygon(points: &[Vec2], center: Vec2, extrude_depth: f32) -> Vec<Triangle> {
+    let top = |p: Vec2| Vec3::new(p.x, p.y, extrude_depth);
+    let bottom = |p: Vec2| Vec3::new(p.x, p.y, 0.0);
+    let top_center = top(center);
+    let bottom_center = bottom(center);
+
+    let n = points.len();
+    let mut triangles = Vec::with_capacity(n * 4);
+    for i in 0..n {
+        let p0 = points[i];
+        let p1 = points[(i + 1) % n];
+
+        triangles.push(Triangle::new(top_center, top(p1), top(p0)));
+        triangles.push(Triangle::new(bottom_center, bottom(p0), bottom(p1)));
+
+        let (top0, top1) = (top(p0), top(p1));
+        let (bottom0, bottom1) = (bottom(p0), bottom(p1));
+        triangles.push(Triangle::new(top0, top1, bottom0));
+        triangles.push(Triangle::new(top1, bottom1, bottom0));
+    }
+    triangles
+}
+
+fn write_vec3(file: &mut File, v: Vec3) -> io::Result<()> {
+    file.write_all(&v.x.to_le_bytes())?;
+    file.write_all(&v.y.to_le_bytes())?;
+    file.write_all(&v.z.to_le_bytes())
+}
+
+/// Writes `triangles` to `path` as a binary STL mesh: an 80-byte zero
+/// header, a little-endian triangle count, then for each triangle its
+/// normal and three vertices as little-endian `f32`s followed by a
+/// trailing zero attribute byte count.
+fn write_stl(path: &str, triangles: &[Triangle]) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(&[0u8; 80])?;
+    file.write_all(&(triangles.len() as u32).to_le_bytes())?;
+    for triangle in triangles {
+        write_vec3(&mut file, triangle.normal)?;
+        for vertex in triangle.vertices {
+            write_vec3(&mut file, vertex)?;
+        }
+        file.write_all(&0u16.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+/// Extrudes `points` into a prism of `extrude_depth` around `center` and
+/// writes the resulting mesh to `path` as a binary STL file.
+pub fn save_extruded_stl(
+    path: &str,
+    points: &[Vec2],
+    center: Vec2,
+    extrude_depth: f32,
+) -> io::Result<()> {
+    let triangles = extrude_polygon(points, center, extrude_depth);
+    write_stl(path, &triangles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::star::StarSpec;
+
+    fn default_star_points() -> Vec<Vec2> {
+        StarSpec {
+            center: Vec2::new(0.0, 0.0),
+            outer_radius: 10.0,
+            inner_radius_multiplier: 1.5,
+            spokes: 5,
+            iterations: 0,
+            colors: Vec::new(),
+        }
+        .outline_points()
+    }
+
+    #[test]
+    fn cap_normals_face_outward() {
+        let points = default_star_points();
+        let extrude_depth = 3.0;
+        let triangles = extrude_polygon(&points, Vec2::new(0.0, 0.0), extrude_depth);
+
+        let top_cap = &triangles[0];
+        let bottom_cap = &triangles[1];
+        assert!(
+            top_cap.normal.z.signum() == extrude_depth.signum(),
+            "top cap (at z = extrude_depth) should face the same way as extrude_depth, got {:?}",
+            top_cap.normal
+        );
+        assert!(
+            bottom_cap.normal.z.signum() == -extrude_depth.signum(),
+            "bottom cap (at z = 0) should face opposite extrude_depth, got {:?}",
+            bottom_cap.normal
+        );
+    }
+
+    #[test]
+    fn triangle_count_is_four_per_edge() {
+        let points = default_star_points();
+        let triangles = extrude_polygon(&points, Vec2::new(0.0, 0.0), 3.0);
+        assert_eq!(triangles.len(), points.len() * 4);
+    }
+
+    #[test]
+    fn write_stl_round_trips_header_and_count() {
+        let points = default_star_points();
+        let path = std::env::temp_dir().join("sv_logo_mesh_test.stl");
+        let path_str = path.to_str().unwrap();
+
+        save_extruded_stl(path_str, &points, Vec2::new(0.0, 0.0), 3.0).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let count = u32::from_le_bytes(bytes[80..84].try_into().unwrap());
+        assert_eq!(count as usize, points.len() * 4);
+        assert_eq!(bytes.len(), 80 + 4 + count as usize * 50);
+    }
+}
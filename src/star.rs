@@ -0,0 +1,195 @@
+//! Star geometry: building the boundary polygon, rendering it as a ring of
+//! wedge-shaped segments, and a [`StarSpec`] builder that lets callers
+//! configure and compose stars without touching this module's internals.
+
+use svg::node::element::path::Data;
+use svg::node::element::Path;
+use ultraviolet::{Rotor2, Vec2};
+
+/// Rounds a closed polygon's corners with `iterations` passes of Chaikin's
+/// corner-cutting subdivision. Each pass replaces every edge `P_i -> P_{i+1}`
+/// (wrapping at the end) with the pair `0.75*P_i + 0.25*P_{i+1}` and
+/// `0.25*P_i + 0.75*P_{i+1}`, roughly doubling the vertex count and
+/// converging towards a quadratic B-spline.
+fn chaikin_smooth(points: &[Vec2], iterations: u32) -> Vec<Vec2> {
+    let mut current = points.to_vec();
+    for _ in 0..iterations {
+        let mut next = Vec::with_capacity(current.len() * 2);
+        for i in 0..current.len() {
+            let p = current[i];
+            let q = current[(i + 1) % current.len()];
+            next.push(p * 0.75 + q * 0.25);
+            next.push(p * 0.25 + q * 0.75);
+        }
+        current = next;
+    }
+    current
+}
+
+/// Computes the closed boundary of a star with `spokes` outer points and
+/// `spokes` inner points, alternating around `center`, then rounds it with
+/// [`chaikin_smooth`]. Shared by [`make_segmented_star`] and the mesh
+/// extrusion in [`crate::mesh`].
+fn star_points(
+    center: Vec2,
+    outer_radius: f32,
+    inner_radius_multiplier: f32,
+    spokes: u32,
+    iterations: u32,
+) -> Vec<Vec2> {
+    let angle = -std::f32::consts::TAU / spokes as f32;
+    let outer_pos_start = Vec2::new(0.0, outer_radius);
+    let inner_radius = inner_radius_multiplier * {
+        let pos_0 = Rotor2::from_angle(angle) * outer_pos_start;
+        let pos_1 = Rotor2::from_angle(angle * (spokes - 1) as f32 / -2.0) * outer_pos_start;
+        let diff = pos_0 - pos_1;
+        let slope = diff.y / diff.x;
+        pos_0.y - slope * pos_0.x
+    };
+    let inner_pos_start = Vec2::new(0.0, inner_radius);
+    let mut points = Vec::with_capacity(spokes as usize * 2);
+
+    for i in 0..spokes {
+        let rotor = Rotor2::from_angle(angle * i as f32);
+        points.push(rotor * -outer_pos_start + center);
+        let rotor = Rotor2::from_angle(angle * i as f32 + angle / 2.0);
+        points.push(rotor * inner_pos_start + center);
+    }
+
+    chaikin_smooth(&points, iterations)
+}
+
+fn make_segmented_star(
+    center: Vec2,
+    outer_radius: f32,
+    inner_radius_multiplier: f32,
+    spokes: usize,
+    iterations: u32,
+    colors: Vec<&str>,
+) -> Vec<Path> {
+    let points = star_points(
+        center,
+        outer_radius,
+        inner_radius_multiplier,
+        spokes as u32,
+        iterations,
+    );
+
+    let mut triangles: Vec<[Vec2; 2]> = Vec::with_capacity(points.len());
+
+    if let Some(point) = points.first() {
+        let mut previous = point;
+        for (i, point) in points.iter().skip(1).enumerate() {
+            if i % 2 == 1 {
+                triangles.push([*point, *previous]);
+            } else {
+                triangles.push([*previous, *point]);
+            }
+            previous = point;
+        }
+        triangles.push([*point, *previous]);
+    }
+
+    let mut output = Vec::with_capacity(spokes * 2);
+    for (i, [v0, v1]) in triangles.iter().enumerate() {
+        let fill = if colors.is_empty() {
+            "#ffffff"
+        } else {
+            colors[i % colors.len()]
+        };
+        let data = Data::new()
+            .move_to((v0.x, v0.y))
+            .line_to((v1.x, v1.y))
+            .line_to((center.x, center.y))
+            .close();
+        output.push(
+            Path::new()
+                .set("fill", fill)
+                .set("stroke", "none")
+                .set("d", data),
+        );
+    }
+    output
+}
+
+/// A self-contained description of a star: its placement, proportions, and
+/// color ramp. Building the paths from a `StarSpec` rather than calling
+/// [`make_segmented_star`] directly lets downstream code compose multiple
+/// stars at arbitrary centers and radii into one document.
+#[derive(Debug, Clone)]
+pub struct StarSpec {
+    pub center: Vec2,
+    pub outer_radius: f32,
+    pub inner_radius_multiplier: f32,
+    pub spokes: u32,
+    /// Chaikin corner-cutting passes; 0 keeps the sharp spikes.
+    pub iterations: u32,
+    /// Fill for each wedge segment, cycling if there are more segments than
+    /// colors. An empty list falls back to white for every segment.
+    pub colors: Vec<String>,
+}
+
+impl StarSpec {
+    /// Builds the star's wedge segments as filled `Path`s, one per edge of
+    /// the boundary polygon (`2 * spokes` when `iterations` is 0, doubling
+    /// with each smoothing pass), cycling through `colors`.
+    pub fn build(&self) -> Vec<Path> {
+        let colors: Vec<&str> = self.colors.iter().map(String::as_str).collect();
+        make_segmented_star(
+            self.center,
+            self.outer_radius,
+            self.inner_radius_multiplier,
+            self.spokes as usize,
+            self.iterations,
+            colors,
+        )
+    }
+
+    /// The star's closed boundary polygon, e.g. for extruding into a mesh.
+    pub fn outline_points(&self) -> Vec<Vec2> {
+        star_points(
+            self.center,
+            self.outer_radius,
+            self.inner_radius_multiplier,
+            self.spokes,
+            self.iterations,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Vec<Vec2> {
+        vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ]
+    }
+
+    #[test]
+    fn zero_iterations_is_a_no_op() {
+        assert_eq!(chaikin_smooth(&square(), 0), square());
+    }
+
+    #[test]
+    fn vertex_count_doubles_per_iteration() {
+        let once = chaikin_smooth(&square(), 1);
+        assert_eq!(once.len(), square().len() * 2);
+
+        let twice = chaikin_smooth(&square(), 2);
+        assert_eq!(twice.len(), square().len() * 4);
+    }
+
+    #[test]
+    fn smoothing_keeps_the_path_closed_without_a_duplicated_seam_vertex() {
+        let smoothed = chaikin_smooth(&square(), 1);
+        // The wrap-around edge (last point back to the first) must produce
+        // its own Q/R pair rather than reusing the first input vertex.
+        assert_ne!(smoothed[0], square()[0]);
+        assert_ne!(smoothed.last(), smoothed.first());
+    }
+}